@@ -0,0 +1,93 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+// Lets the metric methods on `Vector` (`dot`, `norm`, `norm_1`, `norm_inf`,
+// `cosine`) compute and return the type's own precision instead of always
+// widening to `f32`.
+pub trait Field: Copy {
+    type Real: Field<Real = Self::Real> +
+        Copy +
+        Default +
+        PartialOrd +
+        Neg<Output = Self::Real> +
+        Add<Output = Self::Real> +
+        Sub<Output = Self::Real> +
+        Mul<Output = Self::Real> +
+        Div<Output = Self::Real>;
+
+    fn sqrt(self) -> Self::Real;
+    fn abs(self) -> Self::Real;
+    fn zero() -> Self::Real;
+
+    // Fused multiply-accumulate: `self * a + acc`.
+    fn mul_add(self, a: Self, acc: Self::Real) -> Self::Real;
+}
+
+impl Field for f32 {
+    type Real = f32;
+
+    fn sqrt(self) -> f32 {
+        f32::sqrt(self)
+    }
+
+    fn abs(self) -> f32 {
+        f32::abs(self)
+    }
+
+    fn zero() -> f32 {
+        0.
+    }
+
+    fn mul_add(self, a: f32, acc: f32) -> f32 {
+        f32::mul_add(self, a, acc)
+    }
+}
+
+impl Field for f64 {
+    type Real = f64;
+
+    fn sqrt(self) -> f64 {
+        f64::sqrt(self)
+    }
+
+    fn abs(self) -> f64 {
+        f64::abs(self)
+    }
+
+    fn zero() -> f64 {
+        0.
+    }
+
+    fn mul_add(self, a: f64, acc: f64) -> f64 {
+        f64::mul_add(self, a, acc)
+    }
+}
+
+// Integer element types widen to `f32` for their metric computations, the
+// same precision `dot`/`norm`/etc used before this trait existed.
+macro_rules! impl_field_for_int {
+    ($($t:ty),*) => {
+        $(
+            impl Field for $t {
+                type Real = f32;
+
+                fn sqrt(self) -> f32 {
+                    (self as f32).sqrt()
+                }
+
+                fn abs(self) -> f32 {
+                    (self as f32).abs()
+                }
+
+                fn zero() -> f32 {
+                    0.
+                }
+
+                fn mul_add(self, a: $t, acc: f32) -> f32 {
+                    (self as f32).mul_add(a as f32, acc)
+                }
+            }
+        )*
+    };
+}
+
+impl_field_for_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
@@ -0,0 +1,3 @@
+pub mod vector;
+pub mod matrix;
+pub mod field;
@@ -0,0 +1,416 @@
+use std::fmt;
+use std::ops::{
+    Add,
+    Sub,
+    Mul,
+    Index,
+    IndexMut
+};
+
+use crate::vector::Vector;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Matrix<T, const R: usize, const C: usize> {
+    pub data: [[T; C]; R],
+}
+
+impl<T, const R: usize, const C: usize> fmt::Display for Matrix<T, R, C>
+where
+T:
+    fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for i in 0..R {
+            write!(f, "[")?;
+
+            for j in 0..C {
+                if j != 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}", self[(i, j)])?;
+            }
+
+            writeln!(f, "]")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where
+T:
+    Copy +
+    Default
+{
+    fn new(data: [[T; C]; R]) -> Self {
+        Self {
+            data: data
+        }
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for Matrix<T, R, C> {
+    fn from(data: [[T; C]; R]) -> Self {
+        Self {
+            data: data
+        }
+    }
+}
+
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for Matrix<T, R, C> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        if row < R && col < C {
+            &self.data[row][col] // Return a reference to the element at (row, col)
+        } else {
+            panic!("Index out of bounds");
+        }
+    }
+}
+
+// Implement IndexMut trait for mutable access to elements (self[(row, col)] = value)
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for Matrix<T, R, C> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        if row < R && col < C {
+            &mut self.data[row][col] // Return a mutable reference to the element at (row, col)
+        } else {
+            panic!("Index out of bounds");
+        }
+    }
+}
+
+impl<T, const R: usize, const C: usize> Add for Matrix<T, R, C>
+where T:
+    Add<Output = T> +
+    Copy
+{
+    type Output = Self;
+
+    fn add(self, m: Self) -> Self {
+        let mut result = self;
+        for i in 0..R {
+            for j in 0..C {
+                result[(i, j)] = result[(i, j)] + m[(i, j)];
+            }
+        }
+
+        result
+    }
+}
+
+impl<T, const R: usize, const C: usize> Sub for Matrix<T, R, C>
+where T:
+    Sub<Output = T> +
+    Copy
+{
+    type Output = Self;
+
+    fn sub(self, m: Self) -> Self {
+        let mut result = self;
+        for i in 0..R {
+            for j in 0..C {
+                result[(i, j)] = result[(i, j)] - m[(i, j)];
+            }
+        }
+
+        result
+    }
+}
+
+impl<T, const R: usize, const C: usize> Mul<T> for Matrix<T, R, C>
+where T:
+    Mul<Output = T> +
+    Copy
+{
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self {
+        let mut result = self;
+        for i in 0..R {
+            for j in 0..C {
+                result[(i, j)] = result[(i, j)] * scalar;
+            }
+        }
+
+        result
+    }
+}
+
+impl<T, const R: usize, const C: usize, const K: usize> Mul<Matrix<T, C, K>> for Matrix<T, R, C>
+where T:
+    Mul<Output = T> +
+    Add<Output = T> +
+    Copy +
+    Default
+{
+    type Output = Matrix<T, R, K>;
+
+    fn mul(self, m: Matrix<T, C, K>) -> Matrix<T, R, K> {
+        let mut result = [[T::default(); K]; R];
+        for i in 0..R {
+            for k in 0..K {
+                let mut sum = T::default();
+                for j in 0..C {
+                    sum = sum + self[(i, j)] * m[(j, k)];
+                }
+                result[i][k] = sum;
+            }
+        }
+
+        Matrix::from(result)
+    }
+}
+
+impl<T, const R: usize, const C: usize> PartialEq for Matrix<T, R, C>
+where
+T:
+    PartialEq
+{
+    fn eq(&self, matrix: &Self) -> bool {
+        self.data == matrix.data
+    }
+}
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where
+T:
+    Mul<Output = T> +
+    Add<Output = T> +
+    Copy +
+    Default +
+    Into<f32>
+{
+    pub fn mul_vec(&self, vector: &Vector<T, C>) -> Vector<T, R> {
+        let mut result = [T::default(); R];
+        for i in 0..R {
+            let mut sum = T::default();
+            for j in 0..C {
+                sum = sum + self[(i, j)] * vector[j];
+            }
+            result[i] = sum;
+        }
+
+        Vector::from(result)
+    }
+}
+
+const RREF_EPSILON: f32 = 1e-10;
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where
+T:
+    Copy +
+    Into<f32>
+{
+    fn to_f32(self) -> [[f32; C]; R] {
+        let mut data = [[0.; C]; R];
+        for (row, src_row) in data.iter_mut().zip(self.data.iter()) {
+            for (dst, &src) in row.iter_mut().zip(src_row.iter()) {
+                *dst = src.into();
+            }
+        }
+
+        data
+    }
+
+    // Gauss-Jordan elimination with partial pivoting, returning the
+    // reduced row-echelon form alongside the number of pivots found.
+    fn rref(&self) -> (Matrix<f32, R, C>, usize) {
+        let mut data = self.to_f32();
+        let mut pivot_row = 0;
+
+        for col in 0..C {
+            if pivot_row >= R {
+                break;
+            }
+
+            let max_row = (pivot_row..R)
+                .max_by(|&a, &b| data[a][col].abs().partial_cmp(&data[b][col].abs()).unwrap())
+                .unwrap();
+
+            if data[max_row][col].abs() < RREF_EPSILON {
+                continue;
+            }
+
+            data.swap(pivot_row, max_row);
+
+            let pivot = data[pivot_row][col];
+            for value in data[pivot_row].iter_mut() {
+                *value /= pivot;
+            }
+
+            for row in 0..R {
+                if row == pivot_row {
+                    continue;
+                }
+
+                let factor = data[row][col];
+                let pivot = data[pivot_row];
+                for (dst, src) in data[row].iter_mut().zip(pivot.iter()) {
+                    *dst -= factor * src;
+                }
+            }
+
+            pivot_row += 1;
+        }
+
+        (Matrix::from(data), pivot_row)
+    }
+
+    pub fn row_echelon(&self) -> Matrix<f32, R, C> {
+        self.rref().0
+    }
+
+    pub fn rank(&self) -> usize {
+        self.rref().1
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+T:
+    Copy +
+    Into<f32>
+{
+    pub fn determinant(&self) -> f32 {
+        let mut data = self.to_f32();
+        let mut det = 1.;
+
+        for col in 0..N {
+            let max_row = (col..N)
+                .max_by(|&a, &b| data[a][col].abs().partial_cmp(&data[b][col].abs()).unwrap())
+                .unwrap();
+
+            if data[max_row][col].abs() < RREF_EPSILON {
+                return 0.;
+            }
+
+            if max_row != col {
+                data.swap(max_row, col);
+                det = -det;
+            }
+
+            det *= data[col][col];
+
+            for row in (col + 1)..N {
+                let factor = data[row][col] / data[col][col];
+                let pivot = data[col];
+                for (dst, src) in data[row].iter_mut().zip(pivot.iter()).skip(col) {
+                    *dst -= factor * src;
+                }
+            }
+        }
+
+        det
+    }
+
+    pub fn inverse(&self) -> Option<Matrix<f32, N, N>> {
+        let mut left = self.to_f32();
+        let mut right = [[0.; N]; N];
+        for (i, row) in right.iter_mut().enumerate() {
+            row[i] = 1.;
+        }
+
+        let mut pivot_row = 0;
+
+        for col in 0..N {
+            let max_row = (pivot_row..N)
+                .max_by(|&a, &b| left[a][col].abs().partial_cmp(&left[b][col].abs()).unwrap())
+                .unwrap();
+
+            if left[max_row][col].abs() < RREF_EPSILON {
+                return None;
+            }
+
+            left.swap(pivot_row, max_row);
+            right.swap(pivot_row, max_row);
+
+            let pivot = left[pivot_row][col];
+            for value in left[pivot_row].iter_mut() {
+                *value /= pivot;
+            }
+            for value in right[pivot_row].iter_mut() {
+                *value /= pivot;
+            }
+
+            for row in 0..N {
+                if row == pivot_row {
+                    continue;
+                }
+
+                let factor = left[row][col];
+                for k in 0..N {
+                    left[row][k] -= factor * left[pivot_row][k];
+                    right[row][k] -= factor * right[pivot_row][k];
+                }
+            }
+
+            pivot_row += 1;
+        }
+
+        Some(Matrix::from(right))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rank_of_full_rank_matrix() {
+        let m: Matrix<f32, 2, 2> = Matrix::from([[1., 2.], [3., 4.]]);
+        assert_eq!(m.rank(), 2);
+    }
+
+    #[test]
+    fn rank_of_singular_matrix() {
+        let m: Matrix<f32, 2, 2> = Matrix::from([[1., 2.], [2., 4.]]);
+        assert_eq!(m.rank(), 1);
+    }
+
+    #[test]
+    fn row_echelon_of_singular_matrix() {
+        let m: Matrix<f32, 2, 2> = Matrix::from([[1., 2.], [2., 4.]]);
+        let echelon = m.row_echelon();
+        let expected: Matrix<f32, 2, 2> = Matrix::from([[1., 2.], [0., 0.]]);
+
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!((echelon[(i, j)] - expected[(i, j)]).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn determinant_of_known_matrix() {
+        let m: Matrix<f32, 2, 2> = Matrix::from([[1., 2.], [3., 4.]]);
+        assert!((m.determinant() - -2.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn determinant_of_singular_matrix_is_zero() {
+        let m: Matrix<f32, 2, 2> = Matrix::from([[1., 2.], [2., 4.]]);
+        assert_eq!(m.determinant(), 0.);
+    }
+
+    #[test]
+    fn inverse_of_known_matrix() {
+        let m: Matrix<f32, 2, 2> = Matrix::from([[4., 7.], [2., 6.]]);
+        let inverse = m.inverse().expect("matrix is invertible");
+        let expected: Matrix<f32, 2, 2> = Matrix::from([[0.6, -0.7], [-0.2, 0.4]]);
+
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!((inverse[(i, j)] - expected[(i, j)]).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_of_singular_matrix_is_none() {
+        let m: Matrix<f32, 2, 2> = Matrix::from([[1., 2.], [2., 4.]]);
+        assert!(m.inverse().is_none());
+    }
+}
@@ -1,19 +1,152 @@
-use num::Signed;
 use std::fmt;
 use std::ops::{
-    Neg, 
     Add,
+    AddAssign,
     Sub,
+    SubAssign,
     Mul,
+    MulAssign,
     Index,
     IndexMut
 };
 
+use crate::field::Field;
+
 #[derive(Debug, Clone, Copy)]
 pub struct Vector<T, const N: usize> {
     pub data: [T; N],
 }
 
+// Opt-in lane-chunked fast path for `f32` reductions, enabled via the
+// `simd` feature. Dispatch happens per-call by checking `T`'s `TypeId`,
+// since the surrounding API stays generic over `T`.
+#[cfg(feature = "simd")]
+mod simd {
+    use std::any::TypeId;
+
+    const LANES: usize = 8;
+
+    pub fn as_f32_slice<T: 'static>(data: &[T]) -> Option<&[f32]> {
+        if TypeId::of::<T>() == TypeId::of::<f32>() {
+            // Safety: `T` is `f32`, verified above, so this reinterpret is layout-safe.
+            Some(unsafe { std::slice::from_raw_parts(data.as_ptr() as *const f32, data.len()) })
+        } else {
+            None
+        }
+    }
+
+    pub fn as_f32_slice_mut<T: 'static>(data: &mut [T]) -> Option<&mut [f32]> {
+        if TypeId::of::<T>() == TypeId::of::<f32>() {
+            // Safety: `T` is `f32`, verified above, so this reinterpret is layout-safe.
+            Some(unsafe { std::slice::from_raw_parts_mut(data.as_mut_ptr() as *mut f32, data.len()) })
+        } else {
+            None
+        }
+    }
+
+    // Reinterprets an `f32` scalar result as `R` when `R` is itself `f32`,
+    // which holds whenever the fast path above was taken.
+    pub fn from_f32<R: 'static>(x: f32) -> Option<R> {
+        if TypeId::of::<R>() == TypeId::of::<f32>() {
+            // Safety: `R` is `f32`, verified above, so this reinterpret is layout-safe.
+            Some(unsafe { std::mem::transmute_copy(&x) })
+        } else {
+            None
+        }
+    }
+
+    pub fn dot(a: &[f32], b: &[f32]) -> f32 {
+        let chunks = a.len() / LANES;
+        let mut acc = [0.; LANES];
+
+        for c in 0..chunks {
+            let base = c * LANES;
+            for l in 0..LANES {
+                acc[l] += a[base + l] * b[base + l];
+            }
+        }
+
+        let mut sum: f32 = acc.iter().sum();
+        for i in (chunks * LANES)..a.len() {
+            sum += a[i] * b[i];
+        }
+
+        sum
+    }
+
+    pub fn norm_1(a: &[f32]) -> f32 {
+        let chunks = a.len() / LANES;
+        let mut acc = [0.; LANES];
+
+        for c in 0..chunks {
+            let base = c * LANES;
+            for l in 0..LANES {
+                acc[l] += a[base + l].abs();
+            }
+        }
+
+        let mut sum: f32 = acc.iter().sum();
+        for i in (chunks * LANES)..a.len() {
+            sum += a[i].abs();
+        }
+
+        sum
+    }
+
+    pub fn norm_sq(a: &[f32]) -> f32 {
+        dot(a, a)
+    }
+
+    pub fn norm_inf(a: &[f32]) -> f32 {
+        let chunks = a.len() / LANES;
+        let mut acc = [0.; LANES];
+
+        for c in 0..chunks {
+            let base = c * LANES;
+            for l in 0..LANES {
+                acc[l] = f32::max(acc[l], a[base + l].abs());
+            }
+        }
+
+        let mut max = acc.iter().copied().fold(0., f32::max);
+        for i in (chunks * LANES)..a.len() {
+            max = f32::max(max, a[i].abs());
+        }
+
+        max
+    }
+
+    pub fn add(a: &[f32], b: &[f32], out: &mut [f32]) {
+        let chunks = a.len() / LANES;
+
+        for c in 0..chunks {
+            let base = c * LANES;
+            for l in 0..LANES {
+                out[base + l] = a[base + l] + b[base + l];
+            }
+        }
+
+        for i in (chunks * LANES)..a.len() {
+            out[i] = a[i] + b[i];
+        }
+    }
+
+    pub fn sub(a: &[f32], b: &[f32], out: &mut [f32]) {
+        let chunks = a.len() / LANES;
+
+        for c in 0..chunks {
+            let base = c * LANES;
+            for l in 0..LANES {
+                out[base + l] = a[base + l] - b[base + l];
+            }
+        }
+
+        for i in (chunks * LANES)..a.len() {
+            out[i] = a[i] - b[i];
+        }
+    }
+}
+
 impl<T, const N: usize> fmt::Display for Vector<T, N>
 where
 T:
@@ -77,6 +210,47 @@ impl<T, const N: usize> IndexMut<usize> for Vector<T, N> {
     }
 }
 
+impl<T, const N: usize> Vector<T, N>
+where T:
+    Add<Output = T> +
+    Copy
+{
+    fn add_scalar(self, v: Self) -> Self {
+        let mut result = self.clone();
+        for i in 0..N {
+            result[i] = result[i] + v[i];
+        }
+
+        result
+    }
+}
+
+// The `'static` bound below is only required to dispatch into the simd fast
+// path, so it's confined to the `simd`-enabled impl rather than leaking into
+// the unconditional trait bound.
+#[cfg(feature = "simd")]
+impl<T, const N: usize> Add for Vector<T, N>
+where T:
+    Add<Output = T> +
+    Copy +
+    'static
+{
+    type Output = Self;
+
+    fn add(self, v: Self) -> Self {
+        let mut result = self.clone();
+
+        if let (Some(a), Some(b)) = (simd::as_f32_slice(&self.data), simd::as_f32_slice(&v.data)) {
+            let out = simd::as_f32_slice_mut(&mut result.data).unwrap();
+            simd::add(a, b, out);
+            return result;
+        }
+
+        self.add_scalar(v)
+    }
+}
+
+#[cfg(not(feature = "simd"))]
 impl<T, const N: usize> Add for Vector<T, N>
 where T:
     Add<Output = T> +
@@ -85,29 +259,57 @@ where T:
     type Output = Self;
 
     fn add(self, v: Self) -> Self {
+        self.add_scalar(v)
+    }
+}
+
+impl<T, const N: usize> Vector<T, N>
+where T:
+    Sub<Output = T> +
+    Copy
+{
+    fn sub_scalar(self, v: Self) -> Self {
         let mut result = self.clone();
         for i in 0..N {
-            result[i] = result[i] + v[i];
+            result[i] = result[i] - v[i];
         }
 
         result
     }
 }
 
+#[cfg(feature = "simd")]
 impl<T, const N: usize> Sub for Vector<T, N>
 where T:
     Sub<Output = T> +
-    Copy
+    Copy +
+    'static
 {
     type Output = Self;
 
     fn sub(self, v: Self) -> Self {
         let mut result = self.clone();
-        for i in 0..N {
-            result[i] = result[i] - v[i];
+
+        if let (Some(a), Some(b)) = (simd::as_f32_slice(&self.data), simd::as_f32_slice(&v.data)) {
+            let out = simd::as_f32_slice_mut(&mut result.data).unwrap();
+            simd::sub(a, b, out);
+            return result;
         }
 
-        result
+        self.sub_scalar(v)
+    }
+}
+
+#[cfg(not(feature = "simd"))]
+impl<T, const N: usize> Sub for Vector<T, N>
+where T:
+    Sub<Output = T> +
+    Copy
+{
+    type Output = Self;
+
+    fn sub(self, v: Self) -> Self {
+        self.sub_scalar(v)
     }
 }
 
@@ -128,6 +330,109 @@ where T:
     }
 }
 
+// Generates the `&Vector op &Vector`, `&Vector op Vector` and `Vector op &Vector`
+// combinations for a binary operator by delegating to the existing by-value
+// impl, plus its `*Assign` counterpart, so `v += w` works without cloning.
+// `$($extra)*` carries the `'static` bound the `simd`-enabled `Add`/`Sub` impls
+// require to dispatch into the fast path, so it only applies when that
+// feature is on.
+macro_rules! impl_vector_binop {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt, [$($extra:tt)*]) => {
+        impl<T, const N: usize> $trait<&Vector<T, N>> for Vector<T, N>
+        where T:
+            $trait<Output = T> +
+            Copy
+            $($extra)*
+        {
+            type Output = Self;
+
+            fn $method(self, v: &Vector<T, N>) -> Self {
+                self $op *v
+            }
+        }
+
+        impl<T, const N: usize> $trait<Vector<T, N>> for &Vector<T, N>
+        where T:
+            $trait<Output = T> +
+            Copy
+            $($extra)*
+        {
+            type Output = Vector<T, N>;
+
+            fn $method(self, v: Vector<T, N>) -> Vector<T, N> {
+                *self $op v
+            }
+        }
+
+        impl<T, const N: usize> $trait<&Vector<T, N>> for &Vector<T, N>
+        where T:
+            $trait<Output = T> +
+            Copy
+            $($extra)*
+        {
+            type Output = Vector<T, N>;
+
+            fn $method(self, v: &Vector<T, N>) -> Vector<T, N> {
+                *self $op *v
+            }
+        }
+
+        impl<T, const N: usize> $assign_trait for Vector<T, N>
+        where T:
+            $trait<Output = T> +
+            Copy
+            $($extra)*
+        {
+            fn $assign_method(&mut self, v: Self) {
+                *self = *self $op v;
+            }
+        }
+
+        impl<T, const N: usize> $assign_trait<&Vector<T, N>> for Vector<T, N>
+        where T:
+            $trait<Output = T> +
+            Copy
+            $($extra)*
+        {
+            fn $assign_method(&mut self, v: &Vector<T, N>) {
+                *self = *self $op *v;
+            }
+        }
+    };
+}
+
+#[cfg(feature = "simd")]
+impl_vector_binop!(Add, add, AddAssign, add_assign, +, [+ 'static]);
+#[cfg(feature = "simd")]
+impl_vector_binop!(Sub, sub, SubAssign, sub_assign, -, [+ 'static]);
+
+#[cfg(not(feature = "simd"))]
+impl_vector_binop!(Add, add, AddAssign, add_assign, +, []);
+#[cfg(not(feature = "simd"))]
+impl_vector_binop!(Sub, sub, SubAssign, sub_assign, -, []);
+
+impl<T, const N: usize> Mul<T> for &Vector<T, N>
+where T:
+    Mul<Output = T> +
+    Copy
+{
+    type Output = Vector<T, N>;
+
+    fn mul(self, scalar: T) -> Vector<T, N> {
+        *self * scalar
+    }
+}
+
+impl<T, const N: usize> MulAssign<T> for Vector<T, N>
+where T:
+    Mul<Output = T> +
+    Copy
+{
+    fn mul_assign(&mut self, scalar: T) {
+        *self = *self * scalar;
+    }
+}
+
 impl<T, const N: usize> PartialEq for Vector<T, N>
 where 
 T:
@@ -140,77 +445,134 @@ T:
 
 impl<T, const N: usize> Vector<T, N>
 where
-T: 
-    Mul<Output = T> +
-    Add<Output = T> +
-    Copy + 
-    Into<f32> +
-    Default
+T:
+    Field
 {
-    pub fn dot(&self, vector: &Vector<T, N>) -> f32 {
+    fn dot_scalar(&self, vector: &Vector<T, N>) -> T::Real {
         self.data
             .iter()
             .zip(vector.data.iter())
-            .fold(0., |sum, (&x, &y)| sum + x.into() * y.into())
+            .fold(T::zero(), |sum, (&x, &y)| x.mul_add(y, sum))
     }
-}
 
-impl<T, const N: usize> Vector<T, N>
-where
-T:
-    Neg<Output = T> +
-    Copy + 
-    Signed +
-    Into<f32>
-{
-    pub fn norm_1(&self) -> f32 {
+    fn norm_1_scalar(&self) -> T::Real {
         self.data
             .iter()
-            .fold(0., |sum, &x| sum + x.abs().into())
+            .fold(T::zero(), |sum, &x| sum + x.abs())
+    }
+
+    fn norm_sq_scalar(&self) -> T::Real {
+        self.data
+            .iter()
+            .fold(T::zero(), |sum, &x| x.mul_add(x, sum))
+    }
+
+    fn norm_inf_scalar(&self) -> T::Real {
+        self.data
+            .iter()
+            .fold(T::zero(), |sum, &x| {
+                let ax = x.abs();
+                if ax > sum { ax } else { sum }
+            })
     }
 }
 
+// The `'static` bound below is only required to dispatch into the simd fast
+// path, so it's confined to the `simd`-enabled impls rather than leaking into
+// the unconditional `Field` bound.
+#[cfg(feature = "simd")]
 impl<T, const N: usize> Vector<T, N>
 where
 T:
-    Copy +
-    Into<f32> +
-    Signed
+    Field +
+    'static
 {
-    pub fn norm(&self) -> f32 {
-        self.data
-            .iter()
-            .fold(0., |sum, &x| sum + x.abs().into().powf(2.))
-            .powf(0.5)
+    pub fn dot(&self, vector: &Vector<T, N>) -> T::Real {
+        if let (Some(a), Some(b)) = (simd::as_f32_slice(&self.data), simd::as_f32_slice(&vector.data)) {
+            if let Some(result) = simd::from_f32::<T::Real>(simd::dot(a, b)) {
+                return result;
+            }
+        }
+
+        self.dot_scalar(vector)
+    }
+
+    pub fn norm_1(&self) -> T::Real {
+        if let Some(a) = simd::as_f32_slice(&self.data) {
+            if let Some(result) = simd::from_f32::<T::Real>(simd::norm_1(a)) {
+                return result;
+            }
+        }
+
+        self.norm_1_scalar()
+    }
+
+    pub fn norm(&self) -> T::Real {
+        if let Some(a) = simd::as_f32_slice(&self.data) {
+            if let Some(result) = simd::from_f32::<T::Real>(simd::norm_sq(a)) {
+                return result.sqrt();
+            }
+        }
+
+        self.norm_sq_scalar().sqrt()
+    }
+
+    pub fn norm_inf(&self) -> T::Real {
+        if let Some(a) = simd::as_f32_slice(&self.data) {
+            if let Some(result) = simd::from_f32::<T::Real>(simd::norm_inf(a)) {
+                return result;
+            }
+        }
+
+        self.norm_inf_scalar()
     }
 }
 
+#[cfg(not(feature = "simd"))]
 impl<T, const N: usize> Vector<T, N>
 where
 T:
-    Copy +
-    Into<f32> +
-    Signed +
-    PartialOrd
+    Field
 {
-    pub fn norm_inf(&self) -> f32 {
-        self.data
-            .iter()
-            .fold(0., |sum, &x| f32::max(sum, x.abs().into()))
+    pub fn dot(&self, vector: &Vector<T, N>) -> T::Real {
+        self.dot_scalar(vector)
+    }
+
+    pub fn norm_1(&self) -> T::Real {
+        self.norm_1_scalar()
+    }
+
+    pub fn norm(&self) -> T::Real {
+        self.norm_sq_scalar().sqrt()
+    }
+
+    pub fn norm_inf(&self) -> T::Real {
+        self.norm_inf_scalar()
     }
 }
 
+// `cosine` only calls `dot`/`norm`, but under the `simd` feature those are
+// only implemented for `T: 'static`, so that bound is needed here too.
+#[cfg(feature = "simd")]
+impl<T, const N: usize> Vector<T, N>
+where T:
+    Field +
+    'static
+{
+    pub fn cosine(&self, v: &Vector<T, N>) -> T::Real {
+        let dot_product = self.dot(v);
+        let u_length = self.norm();
+        let v_length = v.norm();
+        dot_product / (u_length * v_length)
+    }
+}
 
+#[cfg(not(feature = "simd"))]
 impl<T, const N: usize> Vector<T, N>
 where T:
-    Mul<Output = T> +
-    Sub<Output = T> +
-    Copy +
-    Default +
-    Into<f32> +
-    Signed
+    Field
 {
-    pub fn cosine(&self, v: &Vector<T, N>) -> f32 {
+    pub fn cosine(&self, v: &Vector<T, N>) -> T::Real {
         let dot_product = self.dot(v);
         let u_length = self.norm();
         let v_length = v.norm();
@@ -274,3 +636,73 @@ T:
 
     Vector::from(result)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dot_on_f32() {
+        let a: Vector<f32, 3> = Vector::from([1., 2., 3.]);
+        let b: Vector<f32, 3> = Vector::from([4., 5., 6.]);
+        assert_eq!(a.dot(&b), 32.);
+    }
+
+    #[test]
+    fn dot_on_integer() {
+        let a: Vector<i32, 3> = Vector::from([1, 2, 3]);
+        let b: Vector<i32, 3> = Vector::from([4, 5, 6]);
+        assert_eq!(a.dot(&b), 32.);
+    }
+
+    #[test]
+    fn norm_on_f32() {
+        let v: Vector<f32, 2> = Vector::from([3., 4.]);
+        assert_eq!(v.norm(), 5.);
+    }
+
+    #[test]
+    fn norm_on_integer() {
+        let v: Vector<i32, 2> = Vector::from([3, 4]);
+        assert_eq!(v.norm(), 5.);
+    }
+
+    // `N = 10` is both above `simd::LANES` (8) and not a multiple of it, so
+    // these exercise the chunked lane accumulator *and* its scalar remainder
+    // tail under `--features simd`, which every other test in this file (all
+    // `N <= 3`) is too small to reach.
+    #[cfg(feature = "simd")]
+    #[test]
+    fn dot_on_f32_above_lane_width() {
+        let a: Vector<f32, 10> = Vector::from([1., 2., 3., 4., 5., 6., 7., 8., 9., 10.]);
+        let b: Vector<f32, 10> = Vector::from([1., 1., 1., 1., 1., 1., 1., 1., 1., 1.]);
+        assert_eq!(a.dot(&b), 55.);
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn norm_1_on_f32_above_lane_width() {
+        let v: Vector<f32, 10> = Vector::from([-1., 2., -3., 4., -5., 6., -7., 8., -9., 10.]);
+        assert_eq!(v.norm_1(), 55.);
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn norm_inf_on_f32_above_lane_width() {
+        let v: Vector<f32, 10> = Vector::from([1., -2., 3., -4., 5., -6., 7., -8., 9., -10.]);
+        assert_eq!(v.norm_inf(), 10.);
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn add_sub_on_f32_above_lane_width() {
+        let a: Vector<f32, 10> = Vector::from([1., 2., 3., 4., 5., 6., 7., 8., 9., 10.]);
+        let b: Vector<f32, 10> = Vector::from([10., 9., 8., 7., 6., 5., 4., 3., 2., 1.]);
+
+        let sum = a + b;
+        assert_eq!(sum.data, [11.; 10]);
+
+        let diff = b - a;
+        assert_eq!(diff.data, [9., 7., 5., 3., 1., -1., -3., -5., -7., -9.]);
+    }
+}